@@ -95,8 +95,13 @@ class PathsConfig(BaseModel):
     models_dir: str = Field(default="./models", description="Models directory")
 
 
+# Configuration schema version understood by this loader
+CONFIG_VERSION = 1
+
+
 class FKSConfig(BaseModel):
     """FKS configuration model."""
+    version: int = CONFIG_VERSION
     service: ServiceConfig
     database: Optional[DatabaseConfig] = None
     redis: Optional[RedisConfig] = None
@@ -107,6 +112,17 @@ class FKSConfig(BaseModel):
     features: Optional[dict] = None
     service_specific: Optional[dict] = None
 
+    @field_validator("version")
+    @classmethod
+    def validate_version(cls, v):
+        """Reject schema versions this loader doesn't understand."""
+        if v != CONFIG_VERSION:
+            raise ValueError(
+                f"Unsupported config version: {v}. This loader supports version "
+                f"{CONFIG_VERSION}; migrate the file to version {CONFIG_VERSION}"
+            )
+        return v
+
     @field_validator("service", mode="before")
     @classmethod
     def validate_service(cls, v):
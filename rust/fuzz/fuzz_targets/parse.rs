@@ -0,0 +1,33 @@
+//! Fuzz the YAML parse + validate pipeline
+//!
+//! Run from `rust/` with a nightly toolchain and `cargo install cargo-fuzz`:
+//!
+//! ```sh
+//! cargo +nightly fuzz run parse
+//! ```
+//!
+//! Any input must produce `Ok` or a `ConfigError`, never a panic. The target
+//! uses `parse_config_isolated`, so inputs can't reach the filesystem (e.g.
+//! `include: /dev/stdin` or `password_file: /dev/zero`) or the process
+//! environment.
+
+#![no_main]
+
+use std::collections::HashMap;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(contents) = std::str::from_utf8(data) {
+        let vars = HashMap::from([
+            ("FKS_FUZZ_NAME".to_string(), "fks_fuzz".to_string()),
+            ("FKS_FUZZ_PORT".to_string(), "8100".to_string()),
+        ]);
+
+        if let Ok(config) = fks_config::parse_config_isolated(contents, &vars) {
+            let _ = config.validate_all();
+            let _ = config.redacted();
+            let _ = fks_config::get_config_value(&config, "service.port");
+        }
+    }
+});
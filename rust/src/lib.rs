@@ -1,11 +1,12 @@
 //! FKS Standard Configuration Loader for Rust Services
 //!
 //! This crate provides a standardized way to load and validate YAML configuration
-//! files for FKS microservices using serde and serde_yaml.
+//! files for FKS microservices using serde and serde_yaml. JSON files are
+//! accepted too, since JSON is valid YAML.
 //!
 //! # Usage
 //!
-//! ```rust
+//! ```rust,no_run
 //! use fks_config::load_config;
 //! use fks_config::FKSConfig;
 //!
@@ -18,12 +19,14 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{self, BufReader, Read};
 use std::path::{Path, PathBuf};
 
 pub mod error;
+pub mod migrate;
 pub use error::{ConfigError, ConfigResult};
 
 /// Service configuration
@@ -239,9 +242,22 @@ fn default_models_dir() -> String {
     "./models".to_string()
 }
 
+/// Configuration schema version understood by this loader
+pub const CONFIG_VERSION: u32 = 1;
+
+fn default_version() -> u32 {
+    CONFIG_VERSION
+}
+
+/// Placeholder shown instead of sensitive values by [`FKSConfig::redacted`]
+pub const REDACTED: &str = "***";
+
 /// FKS configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FKSConfig {
+    /// Configuration schema version (defaults to [`CONFIG_VERSION`])
+    #[serde(default = "default_version")]
+    pub version: u32,
     /// Service configuration (required)
     pub service: ServiceConfig,
     /// Database configuration (optional)
@@ -257,7 +273,7 @@ pub struct FKSConfig {
     /// Paths configuration (optional)
     pub paths: Option<PathsConfig>,
     /// Feature flags (optional)
-    pub features: Option<std::collections::HashMap<String, bool>>,
+    pub features: Option<HashMap<String, bool>>,
     /// Service-specific configuration (optional)
     #[serde(flatten)]
     pub service_specific: Option<serde_yaml::Value>,
@@ -265,17 +281,107 @@ pub struct FKSConfig {
 
 impl FKSConfig {
     /// Validate configuration
+    ///
+    /// Fails with a single [`ConfigError::ValidationError`] listing every
+    /// problem found. Use [`FKSConfig::validate_all`] to get them separately.
+    /// No file IO is involved, so configurations built in code can be
+    /// checked the same way as loaded ones:
+    ///
+    /// ```rust
+    /// use fks_config::{FKSConfig, ServiceConfig, CONFIG_VERSION};
+    ///
+    /// let mut config = FKSConfig {
+    ///     version: CONFIG_VERSION,
+    ///     service: ServiceConfig {
+    ///         name: "fks_api".to_string(),
+    ///         port: 8000,
+    ///         host: "0.0.0.0".to_string(),
+    ///         environment: "development".to_string(),
+    ///         log_level: "INFO".to_string(),
+    ///     },
+    ///     database: None,
+    ///     redis: None,
+    ///     api: None,
+    ///     auth: None,
+    ///     monitoring: None,
+    ///     paths: None,
+    ///     features: None,
+    ///     service_specific: None,
+    /// };
+    /// assert!(config.validate().is_ok());
+    ///
+    /// config.service.port = 80;
+    /// assert!(config.validate().is_err());
+    /// assert_eq!(config.validate_all().unwrap_err().len(), 1);
+    /// ```
     pub fn validate(&self) -> ConfigResult<()> {
+        self.validate_all().map_err(|errors| {
+            let messages: Vec<String> = errors
+                .into_iter()
+                .map(|error| match error {
+                    ConfigError::ValidationError(msg) => msg,
+                    other => other.to_string(),
+                })
+                .collect();
+            ConfigError::ValidationError(messages.join("; "))
+        })
+    }
+
+    /// Copy of the configuration with sensitive values replaced by
+    /// [`REDACTED`]
+    ///
+    /// Use this for anything human-facing (logs, debug output); the original
+    /// configuration keeps the real values. Service-specific values are
+    /// hidden too when their key is `password`, `secret` or `token`, or ends
+    /// in `_password`, `_secret`, `_token` or `_key` (e.g. `mt5.password`,
+    /// `jwt_secret`, `api_key`).
+    pub fn redacted(&self) -> FKSConfig {
+        let redact = |value: &mut Option<String>| {
+            if value.is_some() {
+                *value = Some(REDACTED.to_string());
+            }
+        };
+
+        let mut config = self.clone();
+        if let Some(database) = config.database.as_mut() {
+            redact(&mut database.password);
+        }
+        if let Some(redis) = config.redis.as_mut() {
+            redact(&mut redis.password);
+        }
+        if let Some(auth) = config.auth.as_mut() {
+            redact(&mut auth.secret_key);
+        }
+        if let Some(service_specific) = config.service_specific.as_mut() {
+            redact_sensitive_values(service_specific);
+        }
+        config
+    }
+
+    /// Validate configuration, collecting every problem instead of stopping
+    /// at the first one
+    pub fn validate_all(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        // Validate schema version
+        if self.version != CONFIG_VERSION {
+            errors.push(ConfigError::ValidationError(format!(
+                "Unsupported config version: {}. This loader supports version {}; \
+                 migrate the file to version {}",
+                self.version, CONFIG_VERSION, CONFIG_VERSION
+            )));
+        }
+
         // Validate service name pattern
         if !self.service.name.starts_with("fks_") {
-            return Err(ConfigError::ValidationError(
+            errors.push(ConfigError::ValidationError(
                 "Service name must start with 'fks_'".to_string(),
             ));
         }
 
         // Validate port range
-        if self.service.port < 1024 || self.service.port > 65535 {
-            return Err(ConfigError::ValidationError(
+        if self.service.port < 1024 {
+            errors.push(ConfigError::ValidationError(
                 "Service port must be between 1024 and 65535".to_string(),
             ));
         }
@@ -283,7 +389,7 @@ impl FKSConfig {
         // Validate environment
         let valid_environments = ["development", "staging", "production", "test"];
         if !valid_environments.contains(&self.service.environment.as_str()) {
-            return Err(ConfigError::ValidationError(format!(
+            errors.push(ConfigError::ValidationError(format!(
                 "Invalid environment: {}. Must be one of: {:?}",
                 self.service.environment, valid_environments
             )));
@@ -292,56 +398,693 @@ impl FKSConfig {
         // Validate log level
         let valid_log_levels = ["DEBUG", "INFO", "WARNING", "ERROR", "CRITICAL"];
         if !valid_log_levels.contains(&self.service.log_level.as_str()) {
-            return Err(ConfigError::ValidationError(format!(
+            errors.push(ConfigError::ValidationError(format!(
                 "Invalid log level: {}. Must be one of: {:?}",
                 self.service.log_level, valid_log_levels
             )));
         }
 
-        Ok(())
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Service-specific keys whose values [`FKSConfig::redacted`] hides, alone
+/// or as a `_` suffix (e.g. `api_secret`, `db_password`)
+const SENSITIVE_KEYS: [&str; 3] = ["password", "secret", "token"];
+
+/// Whether a service-specific key names a sensitive value
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.ends_with("_key")
+        || SENSITIVE_KEYS
+            .iter()
+            .any(|sensitive| key == *sensitive || key.ends_with(&format!("_{}", sensitive)))
+}
+
+/// Replace the values of sensitive keys anywhere below `value` with
+/// [`REDACTED`]
+fn redact_sensitive_values(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(mapping) => {
+            for (key, item) in mapping.iter_mut() {
+                if key.as_str().map(is_sensitive_key).unwrap_or(false) {
+                    *item = REDACTED.into();
+                } else {
+                    redact_sensitive_values(item);
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(sequence) => {
+            sequence.iter_mut().for_each(redact_sensitive_values);
+        }
+        _ => {}
+    }
+}
+
+/// Input path that selects stdin instead of a file
+pub const STDIN_PATH: &str = "-";
+
+/// Open a configuration input, treating [`STDIN_PATH`] as stdin
+fn open_input(path: &Path) -> ConfigResult<Box<dyn Read>> {
+    if path == Path::new(STDIN_PATH) {
+        return Ok(Box::new(io::stdin()));
+    }
+
+    let file = File::open(path)
+        .map_err(|e| ConfigError::FileError(format!("Failed to open file: {}", e)))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Human-readable name of a configuration input for error messages
+fn input_name(path: &Path) -> String {
+    if path == Path::new(STDIN_PATH) {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
     }
 }
 
 /// Load YAML configuration file
+///
+/// Pass [`STDIN_PATH`] (`-`) to read the configuration from stdin. After
+/// parsing, the document is processed in this order:
+///
+/// 1. YAML `<<` merge keys are applied
+/// 2. files listed under a top-level `include:` key are merged in (see
+///    [`MAX_INCLUDE_DEPTH`])
+/// 3. `${VAR}` references in string values are expanded
+/// 4. `<field>_file` secrets are read
+/// 5. environment variable overrides are applied
+///
+/// A top-level `profiles:` key is removed before step 3; use
+/// [`load_config_profile`] to apply one.
 pub fn load_yaml<P: AsRef<Path>>(file_path: P) -> ConfigResult<serde_yaml::Value> {
-    let file = File::open(file_path.as_ref())
-        .map_err(|e| ConfigError::FileError(format!("Failed to open file: {}", e)))?;
+    let config = read_yaml(file_path.as_ref())?;
+    prepare_document(config, file_path.as_ref())
+}
 
-    let reader = BufReader::new(file);
-    let config: serde_yaml::Value = serde_yaml::from_reader(reader)
-        .map_err(|e| ConfigError::ParseError(format!("Failed to parse YAML: {}", e)))?;
+/// Parse a single YAML document from `path` without any processing
+fn read_yaml(path: &Path) -> ConfigResult<serde_yaml::Value> {
+    let reader = open_input(path)?;
+    serde_yaml::from_reader(reader).map_err(|e| {
+        ConfigError::ParseError(format!(
+            "Failed to parse YAML from {}: {}",
+            input_name(path),
+            e
+        ))
+    })
+}
+
+/// Load every YAML document in a multi-document file
+///
+/// Documents are separated by `---` and returned in file order, each
+/// processed the same way as [`load_yaml`].
+pub fn load_yaml_documents<P: AsRef<Path>>(file_path: P) -> ConfigResult<Vec<serde_yaml::Value>> {
+    let reader = open_input(file_path.as_ref())?;
+    let mut documents = Vec::new();
+    for (index, document) in serde_yaml::Deserializer::from_reader(reader).enumerate() {
+        let value = serde_yaml::Value::deserialize(document).map_err(|e| {
+            ConfigError::ParseError(format!(
+                "Failed to parse YAML document {} from {}: {}",
+                index,
+                input_name(file_path.as_ref()),
+                e
+            ))
+        })?;
+        documents.push(prepare_document(value, file_path.as_ref())?);
+    }
+
+    Ok(documents)
+}
+
+/// Apply merge keys, resolve includes, interpolate variables, read secret
+/// files and apply env overrides to a document read from `path`
+///
+/// Any `profiles:` key is dropped; only [`load_config_profile`] uses it.
+fn prepare_document(config: serde_yaml::Value, path: &Path) -> ConfigResult<serde_yaml::Value> {
+    let mut config = assemble_document(config, path)?;
+    if let Some(mapping) = config.as_mapping_mut() {
+        mapping.remove(PROFILES_KEY);
+    }
+    resolve_document_values(config, path)
+}
+
+/// Apply merge keys and resolve includes in a document read from `path`
+fn assemble_document(config: serde_yaml::Value, path: &Path) -> ConfigResult<serde_yaml::Value> {
+    // Apply `<<` merge keys
+    let config = apply_merge_keys(config, path)?;
+
+    // Merge included files
+    resolve_root_includes(config, path)
+}
+
+/// Interpolate variables, read secret files and apply env overrides to an
+/// assembled document read from `path`
+fn resolve_document_values(
+    config: serde_yaml::Value,
+    path: &Path,
+) -> ConfigResult<serde_yaml::Value> {
+    let mut config = config;
+
+    // Expand ${VAR} references
+    interpolate_value(&mut config, "", &process_var)?;
+
+    // Read *_file secrets
+    resolve_secret_files(&mut config, &input_dir(path))?;
 
     // Apply environment variable overrides
-    let config = apply_env_overrides(config);
+    apply_env_overrides(config)
+}
 
+/// Apply YAML `<<` merge keys in a document read from `path`
+///
+/// serde_yaml resolves `&anchor`/`*alias` references while parsing, but
+/// leaves `<<: *anchor` as a literal key. Anchors are scoped to a single
+/// document, so an alias can't refer to an anchor in an earlier `---`
+/// document or in an included file.
+fn apply_merge_keys(config: serde_yaml::Value, path: &Path) -> ConfigResult<serde_yaml::Value> {
+    let mut config = config;
+    config.apply_merge().map_err(|e| {
+        ConfigError::ParseError(format!(
+            "Failed to apply YAML merge keys in {}: {}",
+            input_name(path),
+            e
+        ))
+    })?;
     Ok(config)
 }
 
+/// Expand `${VAR}` references in every string below `value`
+///
+/// `field_path` is the dotted path of `value`, used in error messages. For
+/// the numeric and boolean fields in [`TYPED_FIELDS`], a string that is
+/// exactly one reference is re-parsed as a YAML scalar, so
+/// `port: ${PORT:-8100}` yields a number. Every other field stays a string,
+/// even when the variable looks like a number.
+fn interpolate_value(
+    value: &mut serde_yaml::Value,
+    field_path: &str,
+    vars: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<()> {
+    match value {
+        serde_yaml::Value::String(string) => {
+            let retype = is_single_reference(string) && is_typed_field(field_path);
+            let expanded = interpolate_str(string, field_path, vars)?;
+            *value = match serde_yaml::from_str(&expanded) {
+                Ok(scalar @ (serde_yaml::Value::Bool(_) | serde_yaml::Value::Number(_)))
+                    if retype =>
+                {
+                    scalar
+                }
+                _ => serde_yaml::Value::String(expanded),
+            };
+        }
+        serde_yaml::Value::Sequence(sequence) => {
+            for (index, item) in sequence.iter_mut().enumerate() {
+                interpolate_value(item, &format!("{}[{}]", field_path, index), vars)?;
+            }
+        }
+        serde_yaml::Value::Mapping(mapping) => {
+            for (key, item) in mapping.iter_mut() {
+                let key = key
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{:?}", key));
+                let item_path = if field_path.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", field_path, key)
+                };
+                interpolate_value(item, &item_path, vars)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Numeric and boolean fields of each fixed section
+///
+/// The top-level `version` and every `features.<name>` flag are typed too.
+const TYPED_FIELDS: [(&str, &[&str]); 6] = [
+    ("service", &["port"]),
+    ("database", &["port", "pool_size", "max_overflow"]),
+    ("redis", &["port", "db", "decode_responses"]),
+    ("api", &["timeout", "retry_attempts", "retry_delay"]),
+    ("auth", &["token_expiry"]),
+    (
+        "monitoring",
+        &["enabled", "prometheus_port", "health_check_interval"],
+    ),
+];
+
+/// Whether the field at dotted `field_path` holds a number or a boolean
+fn is_typed_field(field_path: &str) -> bool {
+    match field_path.split_once('.') {
+        None => field_path == "version",
+        Some(("features", flag)) => !flag.contains('.'),
+        Some((section, field)) => TYPED_FIELDS
+            .iter()
+            .any(|(name, fields)| *name == section && fields.contains(&field)),
+    }
+}
+
+/// Whether `input` consists of exactly one `${...}` reference
+fn is_single_reference(input: &str) -> bool {
+    input
+        .strip_prefix("${")
+        .and_then(|reference| reference.find('}').map(|end| end == reference.len() - 1))
+        .unwrap_or(false)
+}
+
+/// Look up a `${VAR}` reference in the process environment
+fn process_var(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` references in a string
+///
+/// Variables are looked up with `vars` (normally [`process_var`]). A
+/// reference to an unset variable without a default is a validation error
+/// naming `field_path`. Write `$${` for a literal `${`.
+fn interpolate_str(
+    input: &str,
+    field_path: &str,
+    vars: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            output.push_str(&rest[..start - 1]);
+            output.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        output.push_str(&rest[..start]);
+        let reference = &rest[start + 2..];
+        let end = reference.find('}').ok_or_else(|| {
+            ConfigError::ValidationError(format!(
+                "Unterminated variable reference in {}",
+                field_path
+            ))
+        })?;
+
+        let expression = &reference[..end];
+        let (name, default) = match expression.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expression, None),
+        };
+
+        match (vars(name), default) {
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default)) => output.push_str(default),
+            (None, None) => {
+                return Err(ConfigError::ValidationError(format!(
+                    "Unresolved variable ${{{}}} in {}",
+                    name, field_path
+                )))
+            }
+        }
+
+        rest = &reference[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Top-level key listing other YAML files to merge into a configuration
+const INCLUDE_KEY: &str = "include";
+
+/// Maximum nesting depth of `include:` files
+pub const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Resolve includes of a top-level configuration document read from `path`
+fn resolve_root_includes(
+    config: serde_yaml::Value,
+    path: &Path,
+) -> ConfigResult<serde_yaml::Value> {
+    let mut stack = Vec::new();
+    if path != Path::new(STDIN_PATH) {
+        if let Ok(canonical) = path.canonicalize() {
+            stack.push(canonical);
+        }
+    }
+
+    resolve_includes(config, &input_dir(path), &mut stack, 1)
+}
+
+/// Directory that relative paths in a configuration read from `path` are
+/// resolved against
+fn input_dir(path: &Path) -> PathBuf {
+    if path == Path::new(STDIN_PATH) {
+        PathBuf::from(".")
+    } else {
+        path.parent().map(Path::to_path_buf).unwrap_or_default()
+    }
+}
+
+/// Suffix marking a field whose value is read from a file
+const SECRET_FILE_SUFFIX: &str = "_file";
+
+/// String fields of each fixed section that may be read from a file
+const SECRET_FILE_FIELDS: [(&str, &[&str]); 6] = [
+    ("service", &["name", "host", "environment", "log_level"]),
+    ("database", &["host", "name", "user", "password"]),
+    ("redis", &["host", "password"]),
+    ("api", &["base_url"]),
+    ("auth", &["secret_key", "algorithm"]),
+    (
+        "paths",
+        &["data_dir", "logs_dir", "cache_dir", "models_dir"],
+    ),
+];
+
+/// Replace `<field>_file: path` entries in the fixed sections with `<field>`
+/// set to the trimmed contents of that file
+///
+/// This keeps secrets such as `database.password` out of committed config:
+/// write `password_file: /run/secrets/db_password` instead. Relative paths
+/// are resolved against `base_dir` (paths from included files were already
+/// made relative to their own file by [`anchor_secret_files`]). Only string
+/// fields listed in [`SECRET_FILE_FIELDS`] are resolved; any other `*_file`
+/// key (such as `service.pid_file`) is left alone.
+fn resolve_secret_files(config: &mut serde_yaml::Value, base_dir: &Path) -> ConfigResult<()> {
+    for (section_name, fields) in SECRET_FILE_FIELDS {
+        let section = match config
+            .get_mut(section_name)
+            .and_then(|v| v.as_mapping_mut())
+        {
+            Some(section) => section,
+            None => continue,
+        };
+
+        for field in fields.iter().copied() {
+            let file_key = format!("{}{}", field, SECRET_FILE_SUFFIX);
+            if !section.contains_key(file_key.as_str()) {
+                continue;
+            }
+            if section.contains_key(field) {
+                return Err(ConfigError::ValidationError(format!(
+                    "Both {section}.{field} and {section}.{file_key} are set",
+                    section = section_name,
+                    field = field,
+                    file_key = file_key
+                )));
+            }
+
+            let file_path = match section.remove(file_key.as_str()) {
+                Some(serde_yaml::Value::String(file_path)) => base_dir.join(file_path),
+                other => {
+                    return Err(ConfigError::ValidationError(format!(
+                        "{}.{} must be a file path, got: {:?}",
+                        section_name, file_key, other
+                    )))
+                }
+            };
+
+            let secret = std::fs::read_to_string(&file_path).map_err(|e| {
+                ConfigError::FileError(format!(
+                    "Failed to read {}.{} from {}: {}",
+                    section_name,
+                    file_key,
+                    file_path.display(),
+                    e
+                ))
+            })?;
+            section.insert(field.into(), secret.trim().into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve relative `<field>_file` paths of an included document against
+/// `base_dir`, the directory of the included file
+///
+/// The path is interpolated first so a variable holding an absolute path
+/// still works, and any `${` in the result is escaped again because the
+/// merged document is interpolated once more later on.
+fn anchor_secret_files(config: &mut serde_yaml::Value, base_dir: &Path) -> ConfigResult<()> {
+    for (section_name, fields) in SECRET_FILE_FIELDS {
+        let section = match config
+            .get_mut(section_name)
+            .and_then(|v| v.as_mapping_mut())
+        {
+            Some(section) => section,
+            None => continue,
+        };
+
+        for field in fields {
+            let file_key = format!("{}{}", field, SECRET_FILE_SUFFIX);
+            if let Some(serde_yaml::Value::String(file_path)) = section.get_mut(file_key.as_str()) {
+                let field_path = format!("{}.{}", section_name, file_key);
+                let expanded = interpolate_str(file_path, &field_path, &process_var)?;
+                *file_path = base_dir
+                    .join(expanded)
+                    .to_string_lossy()
+                    .replace("${", "$${");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Merge the files listed under `include:` into `config`
+///
+/// Included files are merged in order, then the including document is merged
+/// on top so its own values win. Paths are relative to `base_dir`, the
+/// directory of the including file. `stack` holds the files currently being
+/// included and is used to detect cycles. `depth` is the nesting level of
+/// `config`, starting at 1 for the root document; it is tracked separately
+/// because stdin has no path to put on `stack`.
+fn resolve_includes(
+    config: serde_yaml::Value,
+    base_dir: &Path,
+    stack: &mut Vec<PathBuf>,
+    depth: usize,
+) -> ConfigResult<serde_yaml::Value> {
+    let mut config = config;
+    let includes = match config
+        .as_mapping_mut()
+        .and_then(|mapping| mapping.remove(INCLUDE_KEY))
+    {
+        Some(includes) => includes,
+        None => return Ok(config),
+    };
+
+    let includes: Vec<String> = match includes {
+        serde_yaml::Value::String(include) => vec![include],
+        serde_yaml::Value::Sequence(includes) => includes
+            .into_iter()
+            .map(|include| match include {
+                serde_yaml::Value::String(include) => Ok(include),
+                other => Err(ConfigError::ValidationError(format!(
+                    "Include entries must be file paths, got: {:?}",
+                    other
+                ))),
+            })
+            .collect::<ConfigResult<_>>()?,
+        other => {
+            return Err(ConfigError::ValidationError(format!(
+                "Include must be a file path or a list of file paths, got: {:?}",
+                other
+            )))
+        }
+    };
+
+    if depth >= MAX_INCLUDE_DEPTH {
+        return Err(ConfigError::ValidationError(format!(
+            "Includes nested deeper than {} levels",
+            MAX_INCLUDE_DEPTH
+        )));
+    }
+
+    let mut merged = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    for include in includes {
+        let path = base_dir.join(&include);
+        let canonical = path.canonicalize().map_err(|e| {
+            ConfigError::FileError(format!(
+                "Failed to resolve include {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        if stack.contains(&canonical) {
+            return Err(ConfigError::ValidationError(format!(
+                "Include cycle detected: {} is already being included",
+                canonical.display()
+            )));
+        }
+
+        let included = match read_yaml(&canonical)? {
+            serde_yaml::Value::Null => serde_yaml::Value::Mapping(serde_yaml::Mapping::new()),
+            included @ serde_yaml::Value::Mapping(_) => included,
+            other => {
+                return Err(ConfigError::ValidationError(format!(
+                    "Included file {} must contain a mapping, got: {:?}",
+                    canonical.display(),
+                    other
+                )))
+            }
+        };
+        let included = apply_merge_keys(included, &canonical)?;
+
+        let include_dir = canonical
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        stack.push(canonical);
+        let mut included = resolve_includes(included, &include_dir, stack, depth + 1)?;
+        stack.pop();
+        anchor_secret_files(&mut included, &include_dir)?;
+
+        merge_yaml(&mut merged, included);
+    }
+
+    merge_yaml(&mut merged, config);
+    Ok(merged)
+}
+
+/// Prefix for environment variable overrides
+const ENV_PREFIX: &str = "FKS_";
+
 /// Apply environment variable overrides to configuration
-fn apply_env_overrides(config: serde_yaml::Value) -> serde_yaml::Value {
-    // This is a simplified implementation
-    // In practice, you might want to use a more sophisticated approach
-    // that handles nested structures and type conversions
-    
-    // For now, we'll just return the config as-is
-    // Environment variables can be applied during deserialization
-    // by checking env vars in the deserialize functions
-    
-    config
+///
+/// Every scalar already present in the configuration can be overridden by an
+/// environment variable named `FKS_<SECTION>_<KEY>` (e.g. `FKS_SERVICE_PORT`,
+/// `FKS_DATABASE_HOST`), matching the Python loader. The override is parsed to
+/// the type of the value it replaces.
+fn apply_env_overrides(config: serde_yaml::Value) -> ConfigResult<serde_yaml::Value> {
+    let mut config = config;
+    override_mapping(&mut config, ENV_PREFIX)?;
+    Ok(config)
+}
+
+/// Recursively apply environment variable overrides below `prefix`
+fn override_mapping(value: &mut serde_yaml::Value, prefix: &str) -> ConfigResult<()> {
+    let mapping = match value.as_mapping_mut() {
+        Some(mapping) => mapping,
+        None => return Ok(()),
+    };
+
+    for (key, value) in mapping.iter_mut() {
+        let key = match key.as_str() {
+            Some(key) => key,
+            None => continue,
+        };
+        let env_key = format!("{}{}", prefix, key.to_uppercase());
+
+        if value.is_mapping() {
+            override_mapping(value, &format!("{}_", env_key))?;
+        } else if let Ok(env_value) = env::var(&env_key) {
+            *value = parse_env_override(&env_key, &env_value, value)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse an environment variable override to the type of the current value
+fn parse_env_override(
+    env_key: &str,
+    env_value: &str,
+    current: &serde_yaml::Value,
+) -> ConfigResult<serde_yaml::Value> {
+    let invalid = |expected: &str| {
+        ConfigError::ParseError(format!(
+            "Invalid value for environment variable {}: expected {}, got '{}'",
+            env_key, expected, env_value
+        ))
+    };
+
+    match current {
+        serde_yaml::Value::Bool(_) => match env_value.to_lowercase().as_str() {
+            "true" | "1" | "yes" => Ok(serde_yaml::Value::Bool(true)),
+            "false" | "0" | "no" => Ok(serde_yaml::Value::Bool(false)),
+            _ => Err(invalid("a boolean")),
+        },
+        serde_yaml::Value::Number(number) if number.is_f64() => env_value
+            .parse::<f64>()
+            .map(serde_yaml::Value::from)
+            .map_err(|_| invalid("a number")),
+        serde_yaml::Value::Number(_) => env_value
+            .parse::<i64>()
+            .map(serde_yaml::Value::from)
+            .map_err(|_| invalid("an integer")),
+        _ => Ok(serde_yaml::Value::String(env_value.to_string())),
+    }
 }
 
 /// Load FKS configuration from YAML file
 pub fn load_config<P: AsRef<Path>>(config_path: P) -> ConfigResult<FKSConfig> {
-    // If config_path is None, try to find config file
-    let path = if config_path.as_ref().to_string_lossy().is_empty() {
-        find_config_file()?
-    } else {
-        config_path.as_ref().to_path_buf()
-    };
+    let path = resolve_config_path(config_path.as_ref())?;
 
     // Load YAML
     let config_data = load_yaml(&path)?;
 
+    config_from_value(config_data)
+}
+
+/// Path to load, searching common locations when `config_path` is empty
+fn resolve_config_path(config_path: &Path) -> ConfigResult<PathBuf> {
+    // If config_path is None, try to find config file
+    if config_path.to_string_lossy().is_empty() {
+        find_config_file()
+    } else {
+        Ok(config_path.to_path_buf())
+    }
+}
+
+/// Parse FKS configuration from a YAML string
+///
+/// Runs the same pipeline as [`load_config`] (includes, interpolation, env
+/// overrides, validation) without reading a file. Relative `include:` paths
+/// are resolved against the current directory.
+pub fn parse_config(contents: &str) -> ConfigResult<FKSConfig> {
+    let config_data: serde_yaml::Value = serde_yaml::from_str(contents)
+        .map_err(|e| ConfigError::ParseError(format!("Failed to parse YAML: {}", e)))?;
+    let config_data = prepare_document(config_data, Path::new(STDIN_PATH))?;
+
+    config_from_value(config_data)
+}
+
+/// Parse FKS configuration from a YAML string without touching the process
+/// environment or the filesystem
+///
+/// Merge keys are applied and `${VAR}` references are expanded from `vars`
+/// only; `include:` and `profiles:` are ignored, and `<field>_file` secrets
+/// and `FKS_*` env overrides are not applied. The result is then
+/// deserialized and validated like [`parse_config`]. The fuzz target uses
+/// this, since every input is handled without IO.
+pub fn parse_config_isolated(
+    contents: &str,
+    vars: &HashMap<String, String>,
+) -> ConfigResult<FKSConfig> {
+    let config_data: serde_yaml::Value = serde_yaml::from_str(contents)
+        .map_err(|e| ConfigError::ParseError(format!("Failed to parse YAML: {}", e)))?;
+    let mut config_data = apply_merge_keys(config_data, Path::new(STDIN_PATH))?;
+    if let Some(mapping) = config_data.as_mapping_mut() {
+        mapping.remove(INCLUDE_KEY);
+        mapping.remove(PROFILES_KEY);
+    }
+    interpolate_value(&mut config_data, "", &|name| vars.get(name).cloned())?;
+
+    config_from_value(config_data)
+}
+
+/// Deserialize and validate FKS configuration from parsed YAML
+fn config_from_value(config_data: serde_yaml::Value) -> ConfigResult<FKSConfig> {
     // Deserialize into FKSConfig
     let config: FKSConfig = serde_yaml::from_value(config_data)
         .map_err(|e| ConfigError::ParseError(format!("Failed to deserialize config: {}", e)))?;
@@ -352,6 +1095,281 @@ pub fn load_config<P: AsRef<Path>>(config_path: P) -> ConfigResult<FKSConfig> {
     Ok(config)
 }
 
+/// Sections with a fixed set of fields
+const FIXED_SECTIONS: [&str; 7] = [
+    "service",
+    "database",
+    "redis",
+    "api",
+    "auth",
+    "monitoring",
+    "paths",
+];
+
+/// Top-level `FKSConfig` fields (everything else is service-specific)
+const TOP_LEVEL_FIELDS: [&str; 9] = [
+    "version",
+    "service",
+    "database",
+    "redis",
+    "api",
+    "auth",
+    "monitoring",
+    "paths",
+    "features",
+];
+
+/// Load FKS configuration, rejecting unknown fields in known sections
+///
+/// A misspelled key such as `service.prot` is normally dropped silently and
+/// the default used instead. In strict mode it fails with a
+/// [`ConfigError::ValidationError`] naming every unexpected key by its path.
+/// Unknown top-level keys are still collected into `service_specific`.
+pub fn load_config_strict<P: AsRef<Path>>(config_path: P) -> ConfigResult<FKSConfig> {
+    let config_data = load_yaml(resolve_config_path(config_path.as_ref())?)?;
+    let config = config_from_value(config_data.clone())?;
+
+    let unknown = unknown_fields(&config_data, &config)?;
+    if !unknown.is_empty() {
+        return Err(ConfigError::ValidationError(format!(
+            "Unknown field(s): {}",
+            unknown.join(", ")
+        )));
+    }
+
+    Ok(config)
+}
+
+/// Paths of keys in known sections of `config_data` that `config` doesn't have
+fn unknown_fields(
+    config_data: &serde_yaml::Value,
+    config: &FKSConfig,
+) -> ConfigResult<Vec<String>> {
+    let known = serde_yaml::to_value(config)
+        .map_err(|e| ConfigError::Other(format!("Failed to serialize config: {}", e)))?;
+
+    let mut unknown = Vec::new();
+    for section in FIXED_SECTIONS {
+        let (input, known) = match (
+            config_data.get(section).and_then(|v| v.as_mapping()),
+            known.get(section).and_then(|v| v.as_mapping()),
+        ) {
+            (Some(input), Some(known)) => (input, known),
+            _ => continue,
+        };
+
+        for key in input.keys() {
+            if !known.contains_key(key) {
+                let key = key
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{:?}", key));
+                unknown.push(format!("{}.{}", section, key));
+            }
+        }
+    }
+
+    Ok(unknown)
+}
+
+/// Top-level key holding named configuration profiles
+const PROFILES_KEY: &str = "profiles";
+
+/// Load FKS configuration with a named profile applied
+///
+/// The file may define a top-level `profiles:` mapping whose entries are
+/// partial configurations (e.g. `dev`, `prod`). The selected profile is
+/// deep-merged onto the root configuration, and the `profiles` key itself is
+/// removed before deserialization. Interpolation, secret files and env
+/// overrides run on the merged result, so an env var still beats a profile.
+pub fn load_config_profile<P: AsRef<Path>>(
+    config_path: P,
+    profile: &str,
+) -> ConfigResult<FKSConfig> {
+    let config_path = resolve_config_path(config_path.as_ref())?;
+    let mut config_data = assemble_document(read_yaml(&config_path)?, &config_path)?;
+
+    let profiles = config_data
+        .as_mapping_mut()
+        .and_then(|mapping| mapping.remove(PROFILES_KEY))
+        .unwrap_or(serde_yaml::Value::Null);
+
+    let available: Vec<String> = profiles
+        .as_mapping()
+        .map(|mapping| {
+            mapping
+                .keys()
+                .filter_map(|key| key.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let overlay = profiles.get(profile).cloned().ok_or_else(|| {
+        ConfigError::ValidationError(format!(
+            "Unknown profile: {}. Available profiles: {:?}",
+            profile, available
+        ))
+    })?;
+    merge_yaml(&mut config_data, overlay);
+    let config_data = resolve_document_values(config_data, &config_path)?;
+
+    config_from_value(config_data)
+}
+
+/// Load FKS configuration from a base file with overlay files applied on top
+///
+/// Overlays are deep-merged onto the base in order, so later overlays win.
+/// Mappings are merged key by key; scalars and sequences in an overlay
+/// replace the base value wholesale. Keys absent from an overlay keep the
+/// value from the base (or an earlier overlay).
+pub fn load_config_with_overlays<P, O>(
+    config_path: P,
+    overlay_paths: &[O],
+) -> ConfigResult<FKSConfig>
+where
+    P: AsRef<Path>,
+    O: AsRef<Path>,
+{
+    // Load base YAML
+    let mut config_data = load_yaml(resolve_config_path(config_path.as_ref())?)?;
+
+    // Merge overlays in order
+    for overlay_path in overlay_paths {
+        let overlay = load_yaml(overlay_path)?;
+        merge_yaml(&mut config_data, overlay);
+    }
+
+    config_from_value(config_data)
+}
+
+/// Load FKS configuration by deep-merging several fragment files
+///
+/// Each file may hold only part of the configuration (e.g. `service.yaml`
+/// and `database.yaml`). Fragments are merged in order with the same rules
+/// as [`load_config_with_overlays`], and the result must still contain every
+/// required field.
+pub fn load_config_fragments<P: AsRef<Path>>(config_paths: &[P]) -> ConfigResult<FKSConfig> {
+    let (first, rest) = config_paths
+        .split_first()
+        .ok_or_else(|| ConfigError::FileError("No configuration fragments given".to_string()))?;
+
+    load_config_with_overlays(first, rest)
+}
+
+/// Deep-merge `overlay` onto `base`
+///
+/// Mappings are merged recursively; any other overlay value replaces the
+/// base value.
+fn merge_yaml(base: &mut serde_yaml::Value, overlay: serde_yaml::Value) {
+    match (base, overlay) {
+        (serde_yaml::Value::Mapping(base_map), serde_yaml::Value::Mapping(overlay_map)) => {
+            for (key, overlay_value) in overlay_map {
+                match base_map.get_mut(&key) {
+                    Some(base_value) => merge_yaml(base_value, overlay_value),
+                    None => {
+                        base_map.insert(key, overlay_value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Load every FKS configuration from a multi-document YAML file
+///
+/// Each document is deserialized and validated independently. Use this when
+/// several environment profiles are kept in one file; [`load_config`] only
+/// accepts single-document files.
+pub fn load_configs<P: AsRef<Path>>(config_path: P) -> ConfigResult<Vec<FKSConfig>> {
+    let documents = load_yaml_documents(resolve_config_path(config_path.as_ref())?)?;
+    if documents.is_empty() {
+        return Err(ConfigError::ParseError(
+            "No YAML documents found in configuration file".to_string(),
+        ));
+    }
+
+    documents
+        .into_iter()
+        .enumerate()
+        .map(|(index, config_data)| {
+            let config: FKSConfig = serde_yaml::from_value(config_data).map_err(|e| {
+                ConfigError::ParseError(format!(
+                    "Failed to deserialize config document {}: {}",
+                    index, e
+                ))
+            })?;
+            config.validate()?;
+            Ok(config)
+        })
+        .collect()
+}
+
+/// File extensions recognised as configuration files by [`load_config_dir`]
+const CONFIG_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
+
+/// Per-file results of loading a directory of configuration files
+pub type ConfigDirResults = Vec<(PathBuf, ConfigResult<FKSConfig>)>;
+
+/// Load and validate every configuration file in a directory
+///
+/// Files with a `.yaml`, `.yml` or `.json` extension are loaded with
+/// [`load_config`] in file-name order. Each file gets its own result, so one
+/// invalid file doesn't hide problems in the others. Only failing to read the
+/// directory itself is an error.
+pub fn load_config_dir<P: AsRef<Path>>(dir: P) -> ConfigResult<ConfigDirResults> {
+    Ok(config_dir_paths(dir.as_ref())?
+        .into_iter()
+        .map(|path| {
+            let result = load_config(&path);
+            (path, result)
+        })
+        .collect())
+}
+
+/// Load configuration files in a directory, stopping at the first invalid one
+///
+/// Like [`load_config_dir`], but the returned results end with the first
+/// file that failed to load; later files are not read.
+pub fn load_config_dir_fail_fast<P: AsRef<Path>>(dir: P) -> ConfigResult<ConfigDirResults> {
+    let mut results = Vec::new();
+    for path in config_dir_paths(dir.as_ref())? {
+        let result = load_config(&path);
+        let failed = result.is_err();
+        results.push((path, result));
+        if failed {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Configuration files in a directory, sorted by file name
+fn config_dir_paths(dir: &Path) -> ConfigResult<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        ConfigError::FileError(format!("Failed to read directory {}: {}", dir.display(), e))
+    })?;
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| ConfigError::FileError(format!("Failed to read directory entry: {}", e)))?
+            .path();
+        let is_config = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| CONFIG_EXTENSIONS.contains(&extension))
+            .unwrap_or(false);
+        if is_config && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    Ok(paths)
+}
+
 /// Find configuration file in common locations
 fn find_config_file() -> ConfigResult<PathBuf> {
     // Check environment variable
@@ -371,6 +1389,8 @@ fn find_config_file() -> ConfigResult<PathBuf> {
         current_dir.join("config.yml"),
         current_dir.join("config").join("config.yaml"),
         current_dir.join("config").join("config.yml"),
+        current_dir.join("config.json"),
+        current_dir.join("config").join("config.json"),
     ];
 
     for candidate in &candidates {
@@ -381,27 +1401,151 @@ fn find_config_file() -> ConfigResult<PathBuf> {
 
     Err(ConfigError::FileError(
         "Configuration file not found. Set FKS_CONFIG_PATH environment variable \
-         or place config.yaml in current directory or config/ subdirectory."
+         or place config.yaml (or config.json) in current directory or config/ subdirectory."
             .to_string(),
     ))
 }
 
 /// Get configuration value by key path (dot-separated)
+///
+/// Paths address the configuration as it would be serialized, e.g.
+/// `service.port` or a service-specific top-level key.
 pub fn get_config_value(config: &FKSConfig, key_path: &str) -> Option<serde_yaml::Value> {
-    // Simplified implementation
-    // In practice, you might want to use a more sophisticated approach
-    // that handles nested structures
-    
+    let config = serde_yaml::to_value(config).ok()?;
+
+    key_path
+        .split('.')
+        .try_fold(&config, |value, key| value.get(key))
+        .cloned()
+}
+
+/// Load FKS configuration with `key=value` overrides applied
+///
+/// Each override sets the value at a dot-separated key path (e.g.
+/// `service.port=9000`) after the file is loaded and before validation.
+/// Overrides apply in order, so a later override of the same key wins.
+/// Values are parsed as YAML, so numbers and booleans keep their type; quote
+/// values meant for string fields when they look like something else (e.g.
+/// `database.password='12345'`). Unknown fields in the fixed sections (also
+/// inside a mapping value such as `database={hots: x}`), top-level keys that
+/// are neither `FKSConfig` fields nor already in the file, and values of the
+/// wrong type are errors naming the offending override.
+pub fn load_config_with_overrides<P, S>(config_path: P, overrides: &[S]) -> ConfigResult<FKSConfig>
+where
+    P: AsRef<Path>,
+    S: AsRef<str>,
+{
+    let mut config_data = load_yaml(resolve_config_path(config_path.as_ref())?)?;
+    let deserializes = |config_data: &serde_yaml::Value| {
+        serde_yaml::from_value::<FKSConfig>(config_data.clone()).is_ok()
+    };
+
+    let mut key_paths = Vec::new();
+    // Override that turned a deserializable document into one that isn't
+    let mut culprit = None;
+    let mut valid = deserializes(&config_data);
+    for config_override in overrides {
+        let config_override = config_override.as_ref();
+        let (key_path, raw_value) = config_override.split_once('=').ok_or_else(|| {
+            ConfigError::ValidationError(format!(
+                "Invalid override '{}': expected key=value",
+                config_override
+            ))
+        })?;
+        let value: serde_yaml::Value = serde_yaml::from_str(raw_value).map_err(|e| {
+            ConfigError::ParseError(format!("Invalid value for override {}: {}", key_path, e))
+        })?;
+
+        let top_level = key_path.split('.').next().unwrap_or_default();
+        if !TOP_LEVEL_FIELDS.contains(&top_level) && config_data.get(top_level).is_none() {
+            return Err(ConfigError::ValidationError(format!(
+                "Unknown field(s) in overrides: {}",
+                key_path
+            )));
+        }
+
+        set_yaml_value(&mut config_data, key_path, value)?;
+        key_paths.push(key_path.to_string());
+
+        let now_valid = deserializes(&config_data);
+        if valid && !now_valid {
+            culprit = Some(config_override);
+        } else if now_valid {
+            culprit = None;
+        }
+        valid = now_valid;
+    }
+
+    let config: FKSConfig =
+        serde_yaml::from_value(config_data.clone()).map_err(|e| match culprit {
+            Some(culprit) => {
+                ConfigError::ParseError(format!("Invalid override '{}': {}", culprit, e))
+            }
+            None => ConfigError::ParseError(format!("Failed to deserialize config: {}", e)),
+        })?;
+    config.validate()?;
+
+    let unknown: Vec<String> = unknown_fields(&config_data, &config)?
+        .into_iter()
+        .filter(|field| {
+            key_paths
+                .iter()
+                .any(|key_path| field == key_path || field.starts_with(&format!("{}.", key_path)))
+        })
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ConfigError::ValidationError(format!(
+            "Unknown field(s) in overrides: {}",
+            unknown.join(", ")
+        )));
+    }
+
+    Ok(config)
+}
+
+/// Set the value at a dot-separated key path, creating missing mappings
+fn set_yaml_value(
+    config: &mut serde_yaml::Value,
+    key_path: &str,
+    value: serde_yaml::Value,
+) -> ConfigResult<()> {
     let keys: Vec<&str> = key_path.split('.').collect();
-    
-    // This is a placeholder - actual implementation would traverse the config
-    // based on the key path
-    None
+    if keys.iter().any(|key| key.is_empty()) {
+        return Err(ConfigError::ValidationError(format!(
+            "Invalid override key path: '{}'",
+            key_path
+        )));
+    }
+
+    let mut current = config;
+    for (depth, key) in keys.iter().enumerate() {
+        if current.is_null() {
+            *current = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+        }
+        let mapping = current.as_mapping_mut().ok_or_else(|| {
+            ConfigError::ValidationError(format!(
+                "Cannot set {}: {} is not a mapping",
+                key_path,
+                keys[..depth].join(".")
+            ))
+        })?;
+
+        if depth == keys.len() - 1 {
+            mapping.insert((*key).into(), value);
+            return Ok(());
+        }
+        current = mapping
+            .entry((*key).into())
+            .or_insert(serde_yaml::Value::Null);
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Write;
 
     #[test]
     fn test_load_config() {
@@ -410,5 +1554,1020 @@ mod tests {
         let result = load_config("nonexistent.yaml");
         assert!(result.is_err());
     }
-}
 
+    fn write_temp_config(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("fks_config_{}_{}.yaml", name, std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn service_config(name: &str, port: u16, environment: &str, log_level: &str) -> FKSConfig {
+        FKSConfig {
+            version: CONFIG_VERSION,
+            service: ServiceConfig {
+                name: name.to_string(),
+                port,
+                host: default_host(),
+                environment: environment.to_string(),
+                log_level: log_level.to_string(),
+            },
+            database: None,
+            redis: None,
+            api: None,
+            auth: None,
+            monitoring: None,
+            paths: None,
+            features: None,
+            service_specific: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_all_collects_every_error() {
+        let config = service_config("api", 80, "prod", "TRACE");
+
+        let errors = config.validate_all().unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors
+            .iter()
+            .all(|e| matches!(e, ConfigError::ValidationError(_))));
+
+        match config.validate() {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("fks_"));
+                assert!(msg.contains("Invalid log level: TRACE"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_config() {
+        let config = service_config("fks_api", 8000, "development", "INFO");
+        assert!(config.validate_all().is_ok());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_input_name_for_stdin() {
+        assert_eq!(input_name(Path::new(STDIN_PATH)), "<stdin>");
+        assert_eq!(input_name(Path::new("config.yaml")), "config.yaml");
+        assert_eq!(input_dir(Path::new(STDIN_PATH)), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_load_config_reads_stdin() {
+        // The child run of this test reads the config its parent pipes in
+        if env::var_os("FKS_CONFIG_STDIN_CHILD").is_some() {
+            let config = load_config(STDIN_PATH).unwrap();
+            assert_eq!(config.service.name, "fks_stdin");
+            return;
+        }
+
+        let mut child = std::process::Command::new(env::current_exe().unwrap())
+            .args(["--exact", "tests::test_load_config_reads_stdin"])
+            .env("FKS_CONFIG_STDIN_CHILD", "1")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(b"service:\n  name: fks_stdin\n")
+            .unwrap();
+
+        assert!(child.wait().unwrap().success());
+    }
+
+    #[test]
+    fn test_stdin_input_resolves_paths_against_current_dir() {
+        // parse_config shares the STDIN_PATH pipeline, so relative paths
+        // must resolve against the current directory
+        let dir = PathBuf::from(format!("fks_config_stdin_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("service.yaml"), "service:\n  name: fks_api\n").unwrap();
+        std::fs::write(dir.join("db_password"), "s3cret\n").unwrap();
+
+        let result = parse_config(&format!(
+            "include: {dir}/service.yaml\ndatabase:\n  password_file: {dir}/db_password\n",
+            dir = dir.display()
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.database.unwrap().password.as_deref(), Some("s3cret"));
+    }
+
+    #[test]
+    fn test_parse_error_names_input_file() {
+        let path = write_temp_config("parse_error", "service: [unclosed\n");
+
+        let result = load_yaml(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        match result {
+            Err(ConfigError::ParseError(msg)) => {
+                assert!(msg.contains(&path.display().to_string()))
+            }
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_env_overrides_replace_existing_values() {
+        env::set_var("FKS_ENV_OVERRIDE_TEST_PORT", "9001");
+        env::set_var("FKS_ENV_OVERRIDE_TEST_ENABLED", "false");
+        env::set_var("FKS_ENV_OVERRIDE_TEST_NESTED_HOST", "db.internal");
+
+        let config: serde_yaml::Value = serde_yaml::from_str(
+            "env_override_test:\n  port: 8000\n  enabled: true\n  nested:\n    host: localhost\n",
+        )
+        .unwrap();
+        let config = apply_env_overrides(config).unwrap();
+
+        let section = &config["env_override_test"];
+        assert_eq!(section["port"].as_i64(), Some(9001));
+        assert_eq!(section["enabled"].as_bool(), Some(false));
+        assert_eq!(section["nested"]["host"].as_str(), Some("db.internal"));
+    }
+
+    #[test]
+    fn test_env_override_rejects_invalid_number() {
+        env::set_var("FKS_ENV_OVERRIDE_INVALID_PORT", "not-a-port");
+
+        let config: serde_yaml::Value =
+            serde_yaml::from_str("env_override_invalid:\n  port: 8000\n").unwrap();
+
+        match apply_env_overrides(config) {
+            Err(ConfigError::ParseError(msg)) => {
+                assert!(msg.contains("FKS_ENV_OVERRIDE_INVALID_PORT"))
+            }
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_env_override_rejects_invalid_boolean() {
+        env::set_var("FKS_ENV_OVERRIDE_BOOL_ENABLED", "ture");
+        env::set_var("FKS_ENV_OVERRIDE_BOOL_DEBUG", "no");
+
+        let config: serde_yaml::Value =
+            serde_yaml::from_str("env_override_bool:\n  debug: true\n").unwrap();
+        let config = apply_env_overrides(config).unwrap();
+        assert_eq!(config["env_override_bool"]["debug"].as_bool(), Some(false));
+
+        let config: serde_yaml::Value =
+            serde_yaml::from_str("env_override_bool:\n  enabled: true\n").unwrap();
+        match apply_env_overrides(config) {
+            Err(ConfigError::ParseError(msg)) => {
+                assert!(msg.contains("FKS_ENV_OVERRIDE_BOOL_ENABLED"));
+                assert!(msg.contains("a boolean"));
+            }
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merge_yaml_replaces_scalars_and_sequences() {
+        let mut base: serde_yaml::Value =
+            serde_yaml::from_str("service:\n  name: fks_api\n  port: 8000\nlist: [1, 2, 3]\n")
+                .unwrap();
+        let overlay: serde_yaml::Value =
+            serde_yaml::from_str("service:\n  port: 9000\nlist: [4]\nextra: true\n").unwrap();
+
+        merge_yaml(&mut base, overlay);
+
+        assert_eq!(base["service"]["name"].as_str(), Some("fks_api"));
+        assert_eq!(base["service"]["port"].as_u64(), Some(9000));
+        assert_eq!(base["list"].as_sequence().unwrap().len(), 1);
+        assert_eq!(base["extra"].as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_load_config_with_overlays_later_wins() {
+        let base = write_temp_config(
+            "overlay_base",
+            "service:\n  name: fks_api\n  port: 8000\n  log_level: INFO\n",
+        );
+        let staging = write_temp_config(
+            "overlay_staging",
+            "service:\n  environment: staging\n  port: 8100\n",
+        );
+        let debug = write_temp_config(
+            "overlay_debug",
+            "service:\n  log_level: DEBUG\n  port: 8200\n",
+        );
+
+        let result = load_config_with_overlays(&base, &[&staging, &debug]);
+        for path in [&base, &staging, &debug] {
+            std::fs::remove_file(path).unwrap();
+        }
+
+        let config = result.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.environment, "staging");
+        assert_eq!(config.service.log_level, "DEBUG");
+        assert_eq!(config.service.port, 8200);
+    }
+
+    #[test]
+    fn test_load_config_profile_merges_selected_profile() {
+        let path = write_temp_config(
+            "profiles",
+            "service:\n  name: fks_api\n  log_level: DEBUG\n\
+             profiles:\n\
+             \x20 dev:\n    service:\n      port: 8001\n\
+             \x20 prod:\n    service:\n      environment: production\n      log_level: WARNING\n",
+        );
+
+        let prod = load_config_profile(&path, "prod");
+        let missing = load_config_profile(&path, "qa");
+        std::fs::remove_file(&path).unwrap();
+
+        let prod = prod.unwrap();
+        assert_eq!(prod.service.name, "fks_api");
+        assert_eq!(prod.service.environment, "production");
+        assert_eq!(prod.service.log_level, "WARNING");
+
+        match missing {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("qa"));
+                assert!(msg.contains("dev"));
+                assert!(msg.contains("prod"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_ignores_unselected_profiles() {
+        env::remove_var("FKS_PROFILE_TEST_UNSET");
+        let path = write_temp_config(
+            "profiles_ignored",
+            "service:\n  name: fks_api\n\
+             profiles:\n\
+             \x20 prod:\n    database:\n      password: ${FKS_PROFILE_TEST_UNSET}\n",
+        );
+
+        let result = load_config(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let config = result.unwrap();
+        assert!(config.database.is_none());
+        assert!(get_config_value(&config, PROFILES_KEY).is_none());
+    }
+
+    #[test]
+    fn test_load_config_profile_env_overrides_and_secrets_win() {
+        env::set_var("FKS_PROFILE_ENV_PORT", "7000");
+        let dir = env::temp_dir().join(format!("fks_config_profile_env_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("db_password"), "s3cret\n").unwrap();
+        std::fs::write(
+            dir.join("config.yaml"),
+            "service:\n  name: fks_api\nprofile_env:\n  port: 8000\n\
+             profiles:\n\
+             \x20 prod:\n    profile_env:\n      port: 9000\n\
+             \x20   database:\n      password_file: db_password\n",
+        )
+        .unwrap();
+
+        let result = load_config_profile(dir.join("config.yaml"), "prod");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let config = result.unwrap();
+        let port = config.service_specific.as_ref().unwrap()["profile_env"]["port"].as_i64();
+        assert_eq!(port, Some(7000));
+        assert_eq!(config.database.unwrap().password.as_deref(), Some("s3cret"));
+    }
+
+    #[test]
+    fn test_loaders_find_config_file_for_empty_path() {
+        let path = write_temp_config(
+            "empty_path",
+            "service:\n  name: fks_api\n  port: 8100\nprofiles:\n  dev: {}\n",
+        );
+        env::set_var("FKS_CONFIG_PATH", &path);
+
+        let strict = load_config_strict("");
+        let profile = load_config_profile("", "dev");
+        let overlays = load_config_with_overlays("", &[] as &[&str]);
+        let overrides = load_config_with_overrides("", &["service.port=8200"]);
+        let documents = load_configs("");
+        env::remove_var("FKS_CONFIG_PATH");
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(strict.unwrap().service.port, 8100);
+        assert_eq!(profile.unwrap().service.port, 8100);
+        assert_eq!(overlays.unwrap().service.port, 8100);
+        assert_eq!(overrides.unwrap().service.port, 8200);
+        assert_eq!(documents.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_load_config_strict_rejects_misspelled_keys() {
+        let path = write_temp_config(
+            "strict",
+            "service:\n  name: fks_api\n  prot: 9000\ndatabase:\n  hots: db\ncustom: true\n",
+        );
+
+        let lenient = load_config(&path);
+        let strict = load_config_strict(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lenient.unwrap().service.port, 8000);
+        match strict {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("service.prot"));
+                assert!(msg.contains("database.hots"));
+                assert!(!msg.contains("custom"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_strict_accepts_known_keys() {
+        let path = write_temp_config(
+            "strict_ok",
+            "service:\n  name: fks_api\n  port: 9000\nredis:\n  db: 2\n",
+        );
+
+        let result = load_config_strict(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(result.unwrap().service.port, 9000);
+    }
+
+    #[test]
+    fn test_load_config_merges_includes() {
+        let dir = env::temp_dir().join(format!("fks_config_include_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("parts")).unwrap();
+        std::fs::write(
+            dir.join("parts").join("service.yaml"),
+            "include: [logging.yaml]\nservice:\n  name: fks_api\n  port: 8100\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("parts").join("logging.yaml"),
+            "service:\n  log_level: DEBUG\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("config.yaml"),
+            "include:\n  - parts/service.yaml\nservice:\n  port: 8200\n",
+        )
+        .unwrap();
+
+        let result = load_config(dir.join("config.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.log_level, "DEBUG");
+        assert_eq!(config.service.port, 8200);
+    }
+
+    #[test]
+    fn test_load_config_applies_merge_keys_in_includes() {
+        let dir = env::temp_dir().join(format!("fks_config_include_merge_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("service.yaml"),
+            "defaults: &d\n  port: 8100\nservice:\n  <<: *d\n  name: fks_api\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("config.yaml"), "include: service.yaml\n").unwrap();
+
+        let result = load_config_strict(dir.join("config.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.port, 8100);
+    }
+
+    #[test]
+    fn test_load_yaml_rejects_non_mapping_includes() {
+        let dir = env::temp_dir().join(format!("fks_config_include_scalar_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("answer.yaml"), "42\n").unwrap();
+        std::fs::write(dir.join("empty.yaml"), "").unwrap();
+        std::fs::write(dir.join("config.yaml"), "include: answer.yaml\n").unwrap();
+        let scalar = load_yaml(dir.join("config.yaml"));
+        std::fs::write(
+            dir.join("config.yaml"),
+            "include: empty.yaml\nservice: {}\n",
+        )
+        .unwrap();
+        let empty = load_yaml(dir.join("config.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        match scalar {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("answer.yaml")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert!(empty.unwrap()["service"].is_mapping());
+    }
+
+    #[test]
+    fn test_include_depth_is_the_same_for_stdin_and_files() {
+        let dir = env::temp_dir().join(format!("fks_config_include_depth_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for level in 1..MAX_INCLUDE_DEPTH {
+            std::fs::write(
+                dir.join(format!("level{}.yaml", level)),
+                format!("include: level{}.yaml\n", level + 1),
+            )
+            .unwrap();
+        }
+        std::fs::write(dir.join(format!("level{}.yaml", MAX_INCLUDE_DEPTH)), "{}\n").unwrap();
+
+        // Below a stdin root the same chain is one level deeper than from level1
+        let from_file = load_yaml(dir.join("level1.yaml"));
+        let from_stdin = parse_config(&format!("include: {}\n", dir.join("level1.yaml").display()));
+        let within_limit = parse_config(&format!(
+            "include: {}\nservice:\n  name: fks_api\n",
+            dir.join("level2.yaml").display()
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(from_file.is_ok());
+        match from_stdin {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("deeper")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert!(within_limit.is_ok());
+    }
+
+    #[test]
+    fn test_load_yaml_rejects_include_cycles() {
+        let dir = env::temp_dir().join(format!("fks_config_include_cycle_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.yaml"), "include: b.yaml\n").unwrap();
+        std::fs::write(dir.join("b.yaml"), "include: a.yaml\n").unwrap();
+
+        let result = load_yaml(dir.join("a.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        match result {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("cycle")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interpolate_str_expands_variables_and_defaults() {
+        env::set_var("FKS_INTERPOLATE_TEST_HOME", "/home/fks");
+        env::remove_var("FKS_INTERPOLATE_TEST_UNSET");
+
+        assert_eq!(
+            interpolate_str(
+                "${FKS_INTERPOLATE_TEST_HOME}/fks/data",
+                "paths.data_dir",
+                &process_var
+            )
+            .unwrap(),
+            "/home/fks/fks/data"
+        );
+        assert_eq!(
+            interpolate_str(
+                "${FKS_INTERPOLATE_TEST_UNSET:-./logs}",
+                "paths.logs_dir",
+                &process_var
+            )
+            .unwrap(),
+            "./logs"
+        );
+        assert_eq!(
+            interpolate_str("plain", "service.name", &process_var).unwrap(),
+            "plain"
+        );
+        assert_eq!(
+            interpolate_str("pa$${ss", "database.password", &process_var).unwrap(),
+            "pa${ss"
+        );
+    }
+
+    #[test]
+    fn test_interpolate_value_retypes_single_references() {
+        env::remove_var("FKS_INTERPOLATE_TEST_PORT");
+        env::remove_var("FKS_INTERPOLATE_TEST_PW");
+        env::set_var("FKS_INTERPOLATE_TEST_DEBUG", "true");
+        env::set_var("FKS_INTERPOLATE_TEST_PIN", "0x10");
+
+        let config = parse_config(
+            "service:\n  name: fks_api\n  port: ${FKS_INTERPOLATE_TEST_PORT:-8100}\n\
+             database:\n  password: \"pa$${ss\"\n\
+             monitoring:\n  enabled: ${FKS_INTERPOLATE_TEST_DEBUG}\n\
+             features:\n  debug: ${FKS_INTERPOLATE_TEST_DEBUG}\n\
+             interpolate_test:\n  debug: ${FKS_INTERPOLATE_TEST_DEBUG}\n  \
+             label: port-${FKS_INTERPOLATE_TEST_PORT:-8100}\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.service.port, 8100);
+        assert_eq!(config.database.unwrap().password.as_deref(), Some("pa${ss"));
+        assert!(config.monitoring.unwrap().enabled);
+        assert_eq!(config.features.unwrap().get("debug"), Some(&true));
+        let section = &config.service_specific.unwrap()["interpolate_test"];
+        assert_eq!(section["debug"].as_str(), Some("true"));
+        assert_eq!(section["label"].as_str(), Some("port-8100"));
+
+        let config = parse_config(
+            "service:\n  name: fks_api\n\
+             database:\n  password: ${FKS_INTERPOLATE_TEST_PW:-123456}\n  \
+             name: \"${FKS_INTERPOLATE_TEST_DEBUG}\"\n\
+             auth:\n  secret_key: ${FKS_INTERPOLATE_TEST_PIN}\n",
+        )
+        .unwrap();
+
+        let database = config.database.unwrap();
+        assert_eq!(database.password.as_deref(), Some("123456"));
+        assert_eq!(database.name.as_deref(), Some("true"));
+        assert_eq!(config.auth.unwrap().secret_key.as_deref(), Some("0x10"));
+    }
+
+    #[test]
+    fn test_interpolate_value_reports_field_path() {
+        env::remove_var("FKS_INTERPOLATE_TEST_MISSING");
+        let mut config: serde_yaml::Value =
+            serde_yaml::from_str("paths:\n  data_dir: \"${FKS_INTERPOLATE_TEST_MISSING}/data\"\n")
+                .unwrap();
+
+        match interpolate_value(&mut config, "", &process_var) {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("FKS_INTERPOLATE_TEST_MISSING"));
+                assert!(msg.contains("paths.data_dir"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_from_string() {
+        let config = parse_config("service:\n  name: fks_api\n  port: 8100\n").unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.port, 8100);
+
+        assert!(matches!(
+            parse_config("service:\n  name: api\n"),
+            Err(ConfigError::ValidationError(_))
+        ));
+        assert!(matches!(
+            parse_config("service: [unclosed\n"),
+            Err(ConfigError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_isolated_uses_only_given_vars() {
+        env::set_var("FKS_ISOLATED_TEST_NAME", "fks_env");
+        let vars = HashMap::from([("FKS_ISOLATED_TEST_PORT".to_string(), "8100".to_string())]);
+
+        let config = parse_config_isolated(
+            "include: /dev/stdin\nservice:\n  name: fks_api\n  \
+             port: ${FKS_ISOLATED_TEST_PORT}\ndatabase:\n  password_file: /dev/zero\n",
+            &vars,
+        )
+        .unwrap();
+        assert_eq!(config.service.port, 8100);
+        assert!(get_config_value(&config, INCLUDE_KEY).is_none());
+        assert!(config.database.unwrap().password.is_none());
+
+        match parse_config_isolated("service:\n  name: ${FKS_ISOLATED_TEST_NAME}\n", &vars) {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("FKS_ISOLATED_TEST_NAME"))
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_from_json() {
+        let path = env::temp_dir().join(format!("fks_config_json_{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            "{\n\t\"service\": {\n\t\t\"name\": \"fks_api\",\n\t\t\"port\": 8100\n\t},\n\
+             \t\"features\": {\"enable_cache\": true}\n}\n",
+        )
+        .unwrap();
+
+        let result = load_config(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.port, 8100);
+        assert_eq!(config.features.unwrap().get("enable_cache"), Some(&true));
+    }
+
+    #[test]
+    fn test_config_version_defaults_and_rejects_unknown() {
+        let config = parse_config("service:\n  name: fks_api\n").unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+
+        match parse_config("version: 2\nservice:\n  name: fks_api\n") {
+            Err(ConfigError::ValidationError(msg)) => {
+                assert!(msg.contains("Unsupported config version: 2"));
+                assert!(msg.contains("migrate"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_reads_secret_files() {
+        let dir = env::temp_dir().join(format!("fks_config_secrets_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("db_password"), "s3cret\n").unwrap();
+        std::fs::write(
+            dir.join("config.yaml"),
+            "service:\n  name: fks_api\ndatabase:\n  password_file: db_password\n\
+             auth:\n  secret_key_file: missing_key\n",
+        )
+        .unwrap();
+
+        let missing = load_config(dir.join("config.yaml"));
+        std::fs::write(
+            dir.join("config.yaml"),
+            "service:\n  name: fks_api\ndatabase:\n  password_file: db_password\n",
+        )
+        .unwrap();
+        let loaded = load_config(dir.join("config.yaml"));
+        std::fs::write(
+            dir.join("config.yaml"),
+            "service:\n  name: fks_api\n  port: 8100\n  pid_file: /run/fks_api.pid\n  \
+             port_file: db_password\n",
+        )
+        .unwrap();
+        let unrelated = load_config(dir.join("config.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let database = loaded.unwrap().database.unwrap();
+        assert_eq!(database.password.as_deref(), Some("s3cret"));
+        let unrelated = unrelated.unwrap();
+        assert_eq!(unrelated.service.port, 8100);
+        assert_eq!(unrelated.service.name, "fks_api");
+        match missing {
+            Err(ConfigError::FileError(msg)) => {
+                assert!(msg.contains("auth.secret_key_file"));
+                assert!(msg.contains("missing_key"));
+            }
+            other => panic!("expected file error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_secret_files_resolve_against_including_file() {
+        let dir =
+            env::temp_dir().join(format!("fks_config_include_secrets_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("parts").join("nested")).unwrap();
+        std::fs::write(dir.join("parts").join("db_password"), "db-s3cret\n").unwrap();
+        std::fs::write(
+            dir.join("parts").join("nested").join("jwt_key"),
+            "jwt-s3cret\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("parts").join("db.yaml"),
+            "include: nested/auth.yaml\ndatabase:\n  password_file: db_password\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("parts").join("nested").join("auth.yaml"),
+            "auth:\n  secret_key_file: jwt_key\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("config.yaml"),
+            "include: parts/db.yaml\nservice:\n  name: fks_api\n",
+        )
+        .unwrap();
+
+        let result = load_config(dir.join("config.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(
+            config.database.unwrap().password.as_deref(),
+            Some("db-s3cret")
+        );
+        assert_eq!(
+            config.auth.unwrap().secret_key.as_deref(),
+            Some("jwt-s3cret")
+        );
+    }
+
+    #[test]
+    fn test_redacted_hides_secrets() {
+        let config = parse_config(
+            "service:\n  name: fks_api\ndatabase:\n  password: db-s3cret\n\
+             redis:\n  password: redis-s3cret\nauth:\n  secret_key: jwt-s3cret\n\
+             mt5:\n  login: 1234\n  password: mt5-s3cret\n\
+             exchanges:\n  - name: binance\n    api_key: exchange-s3cret\n    \
+             api_secret: exchange-api-s3cret\n\
+             gateway:\n  jwt_secret: jwt-api-s3cret\n  access_token: token-s3cret\n  \
+             db_password: db-extra-s3cret\n  token_expiry: 3600\n",
+        )
+        .unwrap();
+
+        let redacted = config.redacted();
+        let output = format!("{:?}", redacted);
+        for secret in [
+            "db-s3cret",
+            "redis-s3cret",
+            "jwt-s3cret",
+            "mt5-s3cret",
+            "exchange-s3cret",
+            "exchange-api-s3cret",
+            "jwt-api-s3cret",
+            "token-s3cret",
+            "db-extra-s3cret",
+        ] {
+            assert!(!output.contains(secret));
+        }
+        assert!(output.contains(REDACTED));
+        let service_specific = redacted.service_specific.unwrap();
+        assert_eq!(service_specific["mt5"]["login"].as_i64(), Some(1234));
+        assert_eq!(
+            service_specific["gateway"]["token_expiry"].as_i64(),
+            Some(3600)
+        );
+        assert_eq!(
+            service_specific["exchanges"][0]["name"].as_str(),
+            Some("binance")
+        );
+
+        assert_eq!(
+            config.database.unwrap().password.as_deref(),
+            Some("db-s3cret")
+        );
+    }
+
+    #[test]
+    fn test_load_config_dir_reports_each_file() {
+        let dir = env::temp_dir().join(format!("fks_config_dir_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b_api.yaml"), "service:\n  name: fks_api\n").unwrap();
+        std::fs::write(dir.join("a_bad.yml"), "service:\n  name: api\n").unwrap();
+        std::fs::write(
+            dir.join("c_data.json"),
+            "{\"service\": {\"name\": \"fks_data\"}}",
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a config").unwrap();
+
+        let results = load_config_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let results = results.unwrap();
+        let names: Vec<_> = results
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["a_bad.yml", "b_api.yaml", "c_data.json"]);
+        assert!(matches!(results[0].1, Err(ConfigError::ValidationError(_))));
+        assert!(results[1].1.is_ok());
+        assert!(results[2].1.is_ok());
+    }
+
+    #[test]
+    fn test_load_config_accepts_bom_and_crlf() {
+        let path = write_temp_config(
+            "bom_crlf",
+            "\u{feff}service:\r\n  name: fks_api\r\n  log_level: DEBUG\r\n",
+        );
+
+        let from_file = load_config(&path);
+        let documents = load_configs(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let config = from_file.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.service.log_level, "DEBUG");
+        assert_eq!(documents.unwrap().len(), 1);
+
+        let config = parse_config("\u{feff}service:\n  name: fks_api\n").unwrap();
+        assert_eq!(config.service.name, "fks_api");
+    }
+
+    #[test]
+    fn test_get_config_value_by_key_path() {
+        let config =
+            parse_config("service:\n  name: fks_api\n  port: 8100\nmt5:\n  timeout_ms: 5000\n")
+                .unwrap();
+
+        assert_eq!(
+            get_config_value(&config, "service.port").and_then(|v| v.as_u64()),
+            Some(8100)
+        );
+        assert_eq!(
+            get_config_value(&config, "mt5.timeout_ms").and_then(|v| v.as_u64()),
+            Some(5000)
+        );
+        assert!(get_config_value(&config, "service.missing").is_none());
+    }
+
+    #[test]
+    fn test_load_config_with_overrides_applies_in_order() {
+        let path = write_temp_config(
+            "overrides",
+            "service:\n  name: fks_api\n  port: 8000\ncustom:\n  retries: 1\n",
+        );
+
+        let result = load_config_with_overrides(
+            &path,
+            &[
+                "service.port=9000",
+                "service.port=9100",
+                "redis.db=3",
+                "monitoring.enabled=false",
+                "custom.retries=2",
+            ],
+        );
+        let unknown = load_config_with_overrides(&path, &["service.prot=9000"]);
+        let unknown_top_level = load_config_with_overrides(&path, &["servce.port=9000"]);
+        let unknown_nested = load_config_with_overrides(&path, &["database={hots: x}"]);
+        let unquoted =
+            load_config_with_overrides(&path, &["service.port=9200", "database.password=12345"]);
+        let quoted = load_config_with_overrides(&path, &["database.password='12345'"]);
+        let wrong_type = load_config_with_overrides(&path, &["service.port=fast"]);
+        let scalar_parent = load_config_with_overrides(&path, &["service.port.value=1"]);
+        std::fs::remove_file(&path).unwrap();
+
+        let config = result.unwrap();
+        assert_eq!(config.service.port, 9100);
+        assert_eq!(config.redis.unwrap().db, 3);
+        assert!(!config.monitoring.unwrap().enabled);
+        assert_eq!(
+            config.service_specific.unwrap()["custom"]["retries"].as_i64(),
+            Some(2)
+        );
+
+        match unknown {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("service.prot")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        match unknown_top_level {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("servce.port")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        match unknown_nested {
+            Err(ConfigError::ValidationError(msg)) => assert!(msg.contains("database.hots")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        match unquoted {
+            Err(ConfigError::ParseError(msg)) => {
+                assert!(msg.contains("'database.password=12345'"));
+                assert!(!msg.contains("service.port"));
+            }
+            other => panic!("expected parse error, got {:?}", other),
+        }
+        assert_eq!(
+            quoted.unwrap().database.unwrap().password.as_deref(),
+            Some("12345")
+        );
+        assert!(matches!(wrong_type, Err(ConfigError::ParseError(_))));
+        assert!(matches!(
+            scalar_parent,
+            Err(ConfigError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_anchors_aliases_and_merge_keys() {
+        let config = parse_config(
+            "defaults: &defaults\n  port: 8100\n  log_level: DEBUG\n\
+             api_port: &api_port 8200\n\
+             service:\n  <<: *defaults\n  name: fks_api\n  log_level: INFO\n\
+             monitoring:\n  prometheus_port: *api_port\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.service.port, 8100);
+        assert_eq!(config.service.log_level, "INFO");
+        assert_eq!(config.monitoring.unwrap().prometheus_port, 8200);
+    }
+
+    #[test]
+    fn test_load_configs_anchors_are_scoped_to_each_document() {
+        let path = write_temp_config(
+            "anchors_multi_doc",
+            "base: &base\n  name: fks_sim\nservice:\n  <<: *base\n\
+             ---\n\
+             base: &base\n  name: fks_live\nservice:\n  <<: *base\n",
+        );
+        let cross_document = write_temp_config(
+            "anchors_cross_doc",
+            "service: &shared\n  name: fks_sim\n---\nservice: *shared\n",
+        );
+
+        let configs = load_configs(&path);
+        let cross = load_configs(&cross_document);
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&cross_document).unwrap();
+
+        let configs = configs.unwrap();
+        assert_eq!(configs[0].service.name, "fks_sim");
+        assert_eq!(configs[1].service.name, "fks_live");
+        assert!(matches!(cross, Err(ConfigError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_syntax_errors_report_line_and_column() {
+        let bad_indentation = "service:\n  name: fks_api\n    port: 8100\n";
+        let path = write_temp_config("syntax_error", bad_indentation);
+
+        let single = load_config(&path);
+        let multi = load_configs(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        for result in [
+            single.map(|_| ()),
+            multi.map(|_| ()),
+            parse_config(bad_indentation).map(|_| ()),
+        ] {
+            match result {
+                Err(ConfigError::ParseError(msg)) => {
+                    assert!(msg.contains("at line 3 column 9"), "{}", msg)
+                }
+                other => panic!("expected parse error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_config_dir_fail_fast_stops_at_first_error() {
+        let dir = env::temp_dir().join(format!("fks_config_dir_fail_fast_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a_api.yaml"), "service:\n  name: fks_api\n").unwrap();
+        std::fs::write(dir.join("b_bad.yaml"), "service:\n  name: api\n").unwrap();
+        std::fs::write(dir.join("c_data.yaml"), "service:\n  name: fks_data\n").unwrap();
+
+        let fail_fast = load_config_dir_fail_fast(&dir);
+        let all = load_config_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let fail_fast = fail_fast.unwrap();
+        assert_eq!(fail_fast.len(), 2);
+        assert!(fail_fast[0].1.is_ok());
+        assert!(fail_fast[1].1.is_err());
+        assert_eq!(all.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_load_config_fragments_merges_partial_files() {
+        let service = write_temp_config("fragment_service", "service:\n  name: fks_api\n");
+        let database = write_temp_config(
+            "fragment_database",
+            "database:\n  host: db.internal\n  name: fks_db\n",
+        );
+
+        let merged = load_config_fragments(&[&service, &database]);
+        let incomplete = load_config_fragments(&[&database]);
+        let empty = load_config_fragments::<&Path>(&[]);
+        std::fs::remove_file(&service).unwrap();
+        std::fs::remove_file(&database).unwrap();
+
+        let config = merged.unwrap();
+        assert_eq!(config.service.name, "fks_api");
+        assert_eq!(config.database.unwrap().host, "db.internal");
+        assert!(matches!(incomplete, Err(ConfigError::ParseError(_))));
+        assert!(matches!(empty, Err(ConfigError::FileError(_))));
+    }
+
+    #[test]
+    fn test_load_configs_multiple_documents() {
+        let path = write_temp_config(
+            "multi_doc",
+            "service:\n  name: fks_sim\n  environment: development\n\
+             ---\n\
+             service:\n  name: fks_live\n  environment: production\n",
+        );
+
+        let configs = load_configs(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].service.name, "fks_sim");
+        assert_eq!(configs[1].service.name, "fks_live");
+        assert_eq!(configs[1].service.environment, "production");
+    }
+
+    #[test]
+    fn test_load_configs_reports_invalid_document() {
+        let path = write_temp_config(
+            "multi_doc_invalid",
+            "service:\n  name: fks_sim\n---\nservice:\n  name: sim\n",
+        );
+
+        let result = load_configs(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+    }
+}
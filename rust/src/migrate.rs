@@ -0,0 +1,150 @@
+//! Configuration version migrations
+
+use crate::error::{ConfigError, ConfigResult};
+use crate::CONFIG_VERSION;
+
+/// A migration upgrading a configuration document by one version
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version the migration upgrades from (it produces `from + 1`)
+    pub from: u32,
+    /// Rewrite the document (field renames, new defaults, etc.)
+    pub apply: fn(serde_yaml::Value) -> ConfigResult<serde_yaml::Value>,
+}
+
+/// Registered migrations, one per version step
+///
+/// Add an entry here whenever [`CONFIG_VERSION`] is bumped.
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Upgrade a configuration document to [`CONFIG_VERSION`]
+///
+/// Documents without a `version` key are treated as version 1. The returned
+/// document has its `version` set to the current version.
+pub fn migrate(config: serde_yaml::Value) -> ConfigResult<serde_yaml::Value> {
+    migrate_with(config, MIGRATIONS, CONFIG_VERSION)
+}
+
+/// Upgrade a configuration document to `target` using `migrations`
+pub fn migrate_with(
+    config: serde_yaml::Value,
+    migrations: &[Migration],
+    target: u32,
+) -> ConfigResult<serde_yaml::Value> {
+    let mut config = config;
+    let mut version = document_version(&config)?;
+
+    if version > target {
+        return Err(ConfigError::ValidationError(format!(
+            "Config version {} is newer than the supported version {}",
+            version, target
+        )));
+    }
+
+    while version < target {
+        let migration = migrations
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or_else(|| {
+                ConfigError::Other(format!(
+                    "No migration registered from config version {}",
+                    version
+                ))
+            })?;
+
+        config = (migration.apply)(config)?;
+        version += 1;
+    }
+
+    if let Some(mapping) = config.as_mapping_mut() {
+        mapping.insert("version".into(), version.into());
+    }
+
+    Ok(config)
+}
+
+/// Read the `version` of a configuration document (1 when absent)
+fn document_version(config: &serde_yaml::Value) -> ConfigResult<u32> {
+    match config.get("version") {
+        None => Ok(1),
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| {
+                ConfigError::ValidationError(format!("Invalid config version: {:?}", version))
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_level(config: serde_yaml::Value) -> ConfigResult<serde_yaml::Value> {
+        let mut config = config;
+        if let Some(service) = config.get_mut("service").and_then(|s| s.as_mapping_mut()) {
+            if let Some(level) = service.remove("level") {
+                service.insert("log_level".into(), level);
+            }
+        }
+        Ok(config)
+    }
+
+    fn add_marker(config: serde_yaml::Value) -> ConfigResult<serde_yaml::Value> {
+        let mut config = config;
+        if let Some(mapping) = config.as_mapping_mut() {
+            mapping.insert("migrated".into(), true.into());
+        }
+        Ok(config)
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from: 1,
+            apply: rename_level,
+        },
+        Migration {
+            from: 2,
+            apply: add_marker,
+        },
+    ];
+
+    #[test]
+    fn test_migrate_current_version_is_identity() {
+        let config: serde_yaml::Value =
+            serde_yaml::from_str("service:\n  name: fks_api\n").unwrap();
+
+        let migrated = migrate(config).unwrap();
+        assert_eq!(migrated["service"]["name"].as_str(), Some("fks_api"));
+        assert_eq!(
+            migrated["version"].as_u64(),
+            Some(u64::from(CONFIG_VERSION))
+        );
+    }
+
+    #[test]
+    fn test_migrate_with_chains_migrations() {
+        let config: serde_yaml::Value =
+            serde_yaml::from_str("service:\n  name: fks_api\n  level: DEBUG\n").unwrap();
+
+        let migrated = migrate_with(config, TEST_MIGRATIONS, 3).unwrap();
+        assert_eq!(migrated["service"]["log_level"].as_str(), Some("DEBUG"));
+        assert_eq!(migrated["migrated"].as_bool(), Some(true));
+        assert_eq!(migrated["version"].as_u64(), Some(3));
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_and_unknown_versions() {
+        let newer: serde_yaml::Value = serde_yaml::from_str("version: 9\n").unwrap();
+        assert!(matches!(
+            migrate_with(newer, TEST_MIGRATIONS, 3),
+            Err(ConfigError::ValidationError(_))
+        ));
+
+        let unregistered: serde_yaml::Value = serde_yaml::from_str("version: 1\n").unwrap();
+        assert!(matches!(
+            migrate_with(unregistered, &[], 2),
+            Err(ConfigError::Other(_))
+        ));
+    }
+}
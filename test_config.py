@@ -95,6 +95,25 @@ def test_validation():
         except ValidationError as e:
             print("  ✅ Validation correctly rejected invalid port")
         
+        # Test unsupported config version
+        invalid_config = {
+            'version': 2,
+            'service': {
+                'name': 'fks_test',
+                'port': 8000
+            }
+        }
+        
+        try:
+            config = FKSConfig(**invalid_config)
+            print("  ❌ Validation should have failed")
+            return False
+        except ValidationError as e:
+            if "migrate" not in str(e):
+                print(f"  ❌ Missing migration hint: {e}")
+                return False
+            print("  ✅ Validation correctly rejected unsupported version")
+        
         return True
     except Exception as e:
         print(f"  ❌ Validation test failed: {e}")